@@ -0,0 +1,219 @@
+//! Cross-database analysis, starting with contrafact detection.
+//!
+//! A "contrafact" is a tune built on someone else's chord changes (rhythm
+//! changes, the blues, ...). We detect them by normalizing each song's chord
+//! progression to a transpose-invariant sequence of `(interval-from-tonic,
+//! quality)` pairs and bucketing songs that share the same sequence.
+
+use crate::models::Song;
+use crate::theory;
+use std::collections::HashMap;
+
+/// Fraction of a normalized progression that must overlap with another for
+/// the two songs to be considered the same contrafact family.
+const OVERLAP_THRESHOLD: f64 = 0.9;
+
+/// Parse a single chord symbol (e.g. `"Dm7b5"`, `"G7"`, `"Cmaj7/E"`) into its
+/// root pitch class and quality suffix. Slash-bass notes are ignored.
+fn parse_chord_symbol(token: &str) -> Option<(u8, String)> {
+    let token = token.split('/').next()?.trim();
+    let mut chars = token.chars();
+    let letter = chars.next()?;
+    if !letter.is_ascii_alphabetic() {
+        return None;
+    }
+    let rest = chars.as_str();
+    let (accidental, quality) = match rest.chars().next() {
+        Some(c @ ('#' | 'b')) => (Some(c), &rest[1..]),
+        _ => (None, rest),
+    };
+    let root = theory::pitch_class(letter, accidental)?;
+    Some((root, quality.to_string()))
+}
+
+/// Strip bar lines and repeat markers, returning individual chord tokens.
+fn tokenize_chords(chords: &str) -> Vec<&str> {
+    chords
+        .split(|c| c == '|' || c == ':')
+        .flat_map(|segment| segment.split_whitespace())
+        .filter(|t| !t.is_empty() && *t != "%")
+        .collect()
+}
+
+/// Collect every chord symbol across all of a song's sections, in order.
+fn song_chord_tokens(song: &Song) -> Vec<&str> {
+    let mut tokens = Vec::new();
+    if let Some(sections) = &song.sections {
+        for section in sections {
+            if let Some(chords) = section.main_segment.as_ref().and_then(|s| s.chords.as_deref()) {
+                tokens.extend(tokenize_chords(chords));
+            }
+            if let Some(endings) = &section.endings {
+                for ending in endings {
+                    if let Some(chords) = ending.chords.as_deref() {
+                        tokens.extend(tokenize_chords(chords));
+                    }
+                }
+            }
+        }
+    }
+    tokens
+}
+
+/// Normalize a song's chord progression to a transpose-invariant sequence of
+/// `(interval-from-tonic, quality)` pairs. The tonic is the song's `key`
+/// (its root only), defaulting to the first parseable chord's root when no
+/// key is known. Returns `None` if no chords could be parsed.
+pub fn normalized_progression(song: &Song) -> Option<Vec<(u8, String)>> {
+    let tokens = song_chord_tokens(song);
+    let parsed: Vec<(u8, String)> = tokens.iter().filter_map(|t| parse_chord_symbol(t)).collect();
+    if parsed.is_empty() {
+        return None;
+    }
+
+    let tonic = song
+        .key
+        .as_deref()
+        .and_then(|key| parse_chord_symbol(key))
+        .map(|(root, _)| root)
+        .unwrap_or(parsed[0].0);
+
+    Some(
+        parsed
+            .into_iter()
+            .map(|(root, quality)| (((root as i32 - tonic as i32).rem_euclid(12)) as u8, quality))
+            .collect(),
+    )
+}
+
+/// Length of the longest common (order-preserving, not necessarily
+/// contiguous) subsequence of `a` and `b`.
+fn longest_common_subsequence_len(a: &[(u8, String)], b: &[(u8, String)]) -> usize {
+    let mut dp = vec![vec![0usize; b.len() + 1]; a.len() + 1];
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            dp[i][j] = if a[i - 1] == b[j - 1] {
+                dp[i - 1][j - 1] + 1
+            } else {
+                dp[i - 1][j].max(dp[i][j - 1])
+            };
+        }
+    }
+    dp[a.len()][b.len()]
+}
+
+/// Fraction of `a` that's covered by its longest common subsequence with
+/// `b`, preserving chord order (two songs with identical chords in a
+/// different order do not count as matching).
+fn overlap_ratio(a: &[(u8, String)], b: &[(u8, String)]) -> f64 {
+    if a.is_empty() {
+        return 0.0;
+    }
+    longest_common_subsequence_len(a, b) as f64 / a.len() as f64
+}
+
+/// Group songs whose normalized chord progressions match exactly or overlap
+/// by at least [`OVERLAP_THRESHOLD`], skipping songs with no parseable
+/// chords. Only clusters with more than one song are returned. Bucketing
+/// and merging are both done over a sorted `Vec` (rather than iterating a
+/// `HashMap` directly) so the result is deterministic across runs on the
+/// same input.
+pub fn find_contrafacts(songs: &[Song]) -> Vec<Vec<&Song>> {
+    let mut exact_buckets: HashMap<Vec<(u8, String)>, Vec<&Song>> = HashMap::new();
+    for song in songs {
+        if let Some(progression) = normalized_progression(song) {
+            exact_buckets.entry(progression).or_default().push(song);
+        }
+    }
+    let mut sorted_buckets: Vec<(Vec<(u8, String)>, Vec<&Song>)> = exact_buckets.into_iter().collect();
+    sorted_buckets.sort_by(|a, b| a.0.cmp(&b.0));
+
+    let mut clusters: Vec<(Vec<(u8, String)>, Vec<&Song>)> = Vec::new();
+    'buckets: for (progression, members) in sorted_buckets {
+        for (cluster_progression, cluster_members) in clusters.iter_mut() {
+            let similar = overlap_ratio(&progression, cluster_progression) >= OVERLAP_THRESHOLD
+                || overlap_ratio(cluster_progression, &progression) >= OVERLAP_THRESHOLD;
+            if similar {
+                cluster_members.extend(members);
+                continue 'buckets;
+            }
+        }
+        clusters.push((progression, members));
+    }
+    clusters.sort_by(|a, b| a.0.cmp(&b.0));
+
+    clusters
+        .into_iter()
+        .map(|(_, members)| members)
+        .filter(|members| members.len() > 1)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{Section, Segment};
+
+    #[test]
+    fn parse_chord_symbol_splits_root_quality_and_bass() {
+        assert_eq!(parse_chord_symbol("Dm7b5"), Some((2, "m7b5".to_string())));
+        assert_eq!(parse_chord_symbol("G7"), Some((7, "7".to_string())));
+        assert_eq!(parse_chord_symbol("Cmaj7/E"), Some((0, "maj7".to_string())));
+        assert_eq!(parse_chord_symbol("notachord"), None);
+    }
+
+    #[test]
+    fn parse_chord_symbol_treats_enharmonics_as_equal() {
+        assert_eq!(parse_chord_symbol("C#7").map(|(root, _)| root), parse_chord_symbol("Db7").map(|(root, _)| root));
+    }
+
+    fn song_with_chords(key: Option<&str>, chords: &str) -> Song {
+        Song {
+            title: "Test Tune".to_string(),
+            composer: None,
+            key: key.map(str::to_string),
+            rhythm: None,
+            time_signature: None,
+            sections: Some(vec![Section {
+                label: Some("A".to_string()),
+                repeats: None,
+                main_segment: Some(Segment { chords: Some(chords.to_string()) }),
+                endings: None,
+            }]),
+        }
+    }
+
+    #[test]
+    fn normalized_progression_is_transpose_invariant() {
+        let in_c = song_with_chords(Some("C"), "| Cmaj7 | Dm7 G7 |");
+        let in_f = song_with_chords(Some("F"), "| Fmaj7 | Gm7 C7 |");
+
+        assert_eq!(normalized_progression(&in_c), normalized_progression(&in_f));
+    }
+
+    #[test]
+    fn normalized_progression_defaults_tonic_to_first_chord_without_a_key() {
+        let song = song_with_chords(None, "| Cmaj7 | Dm7 G7 |");
+        let progression = normalized_progression(&song).unwrap();
+        assert_eq!(progression[0].0, 0, "first chord's root becomes interval 0 when no key is given");
+    }
+
+    #[test]
+    fn normalized_progression_is_none_with_no_parseable_chords() {
+        let song = song_with_chords(Some("C"), "| | |");
+        assert_eq!(normalized_progression(&song), None);
+    }
+
+    #[test]
+    fn find_contrafacts_groups_shared_changes_and_skips_singletons() {
+        let blues_in_c = song_with_chords(Some("C"), "| C7 | F7 | C7 | C7 |");
+        let blues_in_bb = song_with_chords(Some("Bb"), "| Bb7 | Eb7 | Bb7 | Bb7 |");
+        let unrelated = song_with_chords(Some("C"), "| Cmaj7 | Am7 Dm7 G7 |");
+        let songs = vec![blues_in_c, blues_in_bb, unrelated];
+
+        let clusters = find_contrafacts(&songs);
+
+        assert_eq!(clusters.len(), 1);
+        assert_eq!(clusters[0].len(), 2);
+    }
+}
@@ -0,0 +1,254 @@
+//! Interactive TUI browse mode over the loaded database.
+//!
+//! Three navigable panes: Composers, Songs (filtered by the selected
+//! composer), and Song Detail (sections/chords, reusing
+//! [`crate::display::format_song_detailed`]). Tab cycles the active pane,
+//! up/down move the highlighted row, `/` starts an incremental search that
+//! matches titles or composer names (via [`crate::search::search_songs`]),
+//! Esc clears it, and `q` quits.
+
+use crate::display::format_song_detailed;
+use crate::models::Song;
+use crate::search::search_songs;
+use crossterm::event::{self, Event, KeyCode};
+use crossterm::terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen};
+use crossterm::execute;
+use ratatui::backend::CrosstermBackend;
+use ratatui::layout::{Constraint, Direction, Layout};
+use ratatui::style::{Modifier, Style};
+use ratatui::widgets::{Block, Borders, List, ListItem, ListState, Paragraph};
+use ratatui::Terminal;
+use std::error::Error;
+use std::io;
+
+/// The pane that currently has keyboard focus.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Category {
+    Composers,
+    Songs,
+    Detail,
+}
+
+impl Category {
+    fn next(self) -> Self {
+        match self {
+            Category::Composers => Category::Songs,
+            Category::Songs => Category::Detail,
+            Category::Detail => Category::Composers,
+        }
+    }
+
+    fn prev(self) -> Self {
+        match self {
+            Category::Composers => Category::Detail,
+            Category::Songs => Category::Composers,
+            Category::Detail => Category::Songs,
+        }
+    }
+}
+
+/// Navigation state: the active category and the selected row within each
+/// of the composer and song lists.
+struct BrowseState<'a> {
+    songs: &'a [Song],
+    composers: Vec<String>,
+    category: Category,
+    composer_index: usize,
+    song_index: usize,
+    search: Option<String>,
+}
+
+impl<'a> BrowseState<'a> {
+    fn new(songs: &'a [Song]) -> Self {
+        let mut composers: Vec<String> = songs
+            .iter()
+            .filter_map(|s| s.composer.clone())
+            .collect::<std::collections::BTreeSet<_>>()
+            .into_iter()
+            .collect();
+        composers.sort();
+        BrowseState {
+            songs,
+            composers,
+            category: Category::Composers,
+            composer_index: 0,
+            song_index: 0,
+            search: None,
+        }
+    }
+
+    fn selected_composer(&self) -> Option<&str> {
+        self.composers.get(self.composer_index).map(|s| s.as_str())
+    }
+
+    /// Songs for the selected composer, narrowed by the active incremental
+    /// search (title or composer match), if any.
+    fn filtered_songs(&self) -> Vec<&'a Song> {
+        let matching_search: Option<std::collections::HashSet<&str>> = match &self.search {
+            Some(term) if !term.is_empty() => {
+                Some(search_songs(self.songs, term).into_iter().map(|s| s.title.as_str()).collect())
+            }
+            _ => None,
+        };
+        match self.selected_composer() {
+            Some(composer) => self
+                .songs
+                .iter()
+                .filter(|s| s.composer.as_deref() == Some(composer))
+                .filter(|s| matching_search.as_ref().map_or(true, |titles| titles.contains(s.title.as_str())))
+                .collect(),
+            None => Vec::new(),
+        }
+    }
+
+    fn selected_song(&self) -> Option<&'a Song> {
+        self.filtered_songs().into_iter().nth(self.song_index)
+    }
+
+    fn move_row(&mut self, delta: i32) {
+        match self.category {
+            Category::Composers => {
+                let len = self.composers.len();
+                if len == 0 {
+                    return;
+                }
+                self.composer_index = ((self.composer_index as i32 + delta).rem_euclid(len as i32)) as usize;
+                // The Songs pane is scoped to the selected composer, so its
+                // old highlight is meaningless (and possibly out of range)
+                // once the composer changes.
+                self.song_index = 0;
+            }
+            Category::Songs => {
+                let len = self.filtered_songs().len();
+                if len == 0 {
+                    return;
+                }
+                self.song_index = ((self.song_index as i32 + delta).rem_euclid(len as i32)) as usize;
+            }
+            Category::Detail => {}
+        }
+    }
+
+    fn change_category(&mut self, delta: i32) {
+        self.category = if delta >= 0 { self.category.next() } else { self.category.prev() };
+    }
+
+    fn start_search(&mut self) {
+        self.search = Some(String::new());
+    }
+
+    fn push_search_char(&mut self, c: char) {
+        if let Some(term) = &mut self.search {
+            term.push(c);
+            self.song_index = 0;
+        }
+    }
+
+    fn pop_search_char(&mut self) {
+        if let Some(term) = &mut self.search {
+            term.pop();
+            self.song_index = 0;
+        }
+    }
+
+    fn clear_search(&mut self) {
+        self.search = None;
+        self.song_index = 0;
+    }
+}
+
+/// Launch the TUI over `songs`, blocking until the user quits with `q`.
+pub fn run(songs: &[Song]) -> Result<(), Box<dyn Error>> {
+    enable_raw_mode()?;
+    let mut stdout = io::stdout();
+    execute!(stdout, EnterAlternateScreen)?;
+    let backend = CrosstermBackend::new(stdout);
+    let mut terminal = Terminal::new(backend)?;
+
+    let mut state = BrowseState::new(songs);
+    let result = run_loop(&mut terminal, &mut state);
+
+    disable_raw_mode()?;
+    execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
+    terminal.show_cursor()?;
+    result
+}
+
+fn run_loop<B: ratatui::backend::Backend>(
+    terminal: &mut Terminal<B>,
+    state: &mut BrowseState,
+) -> Result<(), Box<dyn Error>> {
+    loop {
+        terminal.draw(|frame| draw(frame, state))?;
+
+        if let Event::Key(key) = event::read()? {
+            if state.search.is_some() {
+                match key.code {
+                    KeyCode::Esc => state.clear_search(),
+                    KeyCode::Enter => state.category = Category::Songs,
+                    KeyCode::Up => state.move_row(-1),
+                    KeyCode::Down => state.move_row(1),
+                    KeyCode::Backspace => state.pop_search_char(),
+                    KeyCode::Char(c) => state.push_search_char(c),
+                    _ => {}
+                }
+                continue;
+            }
+            match key.code {
+                KeyCode::Char('q') => return Ok(()),
+                KeyCode::Tab => state.change_category(1),
+                KeyCode::BackTab => state.change_category(-1),
+                KeyCode::Up => state.move_row(-1),
+                KeyCode::Down => state.move_row(1),
+                KeyCode::Char('/') => {
+                    state.category = Category::Songs;
+                    state.start_search();
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+fn draw(frame: &mut ratatui::Frame, state: &BrowseState) {
+    let columns = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(25), Constraint::Percentage(30), Constraint::Percentage(45)])
+        .split(frame.area());
+
+    let composer_items: Vec<ListItem> = state.composers.iter().map(|c| ListItem::new(c.as_str())).collect();
+    let mut composer_list_state = ListState::default();
+    composer_list_state.select(Some(state.composer_index));
+    frame.render_stateful_widget(
+        List::new(composer_items)
+            .block(Block::default().borders(Borders::ALL).title("Composers"))
+            .highlight_style(Style::default().add_modifier(Modifier::REVERSED)),
+        columns[0],
+        &mut composer_list_state,
+    );
+
+    let songs_title = match &state.search {
+        Some(term) => format!("Songs (/{})", term),
+        None => "Songs".to_string(),
+    };
+    let songs = state.filtered_songs();
+    let song_items: Vec<ListItem> = songs.iter().map(|s| ListItem::new(s.title.as_str())).collect();
+    let mut song_list_state = ListState::default();
+    song_list_state.select(Some(state.song_index));
+    frame.render_stateful_widget(
+        List::new(song_items)
+            .block(Block::default().borders(Borders::ALL).title(songs_title))
+            .highlight_style(Style::default().add_modifier(Modifier::REVERSED)),
+        columns[1],
+        &mut song_list_state,
+    );
+
+    let detail_text = match state.selected_song() {
+        Some(song) => format_song_detailed(song),
+        None => "No song selected".to_string(),
+    };
+    frame.render_widget(
+        Paragraph::new(detail_text).block(Block::default().borders(Borders::ALL).title("Song Detail")),
+        columns[2],
+    );
+}
@@ -0,0 +1,238 @@
+//! Command-line interface definition.
+
+use clap::{Parser, Subcommand};
+use std::path::PathBuf;
+
+#[derive(Parser)]
+#[command(name = "jazz-db")]
+#[command(about = "A CLI tool for searching and analyzing the Jazz Standards database")]
+#[command(long_about = "
+A comprehensive CLI tool for exploring the Jazz Standards database containing 1,382 songs.
+Search by title/composer, filter by musical criteria, view detailed chord progressions,
+and analyze database statistics.
+
+Examples:
+  jazz-db search \"miles davis\"
+  jazz-db filter --key C --rhythm swing
+  jazz-db show \"All Blues\"
+  jazz-db stats --detailed
+")]
+#[command(version)]
+pub struct Cli {
+    /// Load (and write back to) an external database file instead of the
+    /// read-only copy embedded in the binary.
+    #[arg(long, global = true, help = "Path to an external JazzStandards.json to use instead of the embedded copy")]
+    pub db: Option<PathBuf>,
+
+    #[command(subcommand)]
+    pub command: Commands,
+}
+
+#[derive(Subcommand)]
+pub enum Commands {
+    /// Search songs by title or composer (partial matching)
+    #[command(long_about = "Search for songs by title or composer name using partial matching.
+
+Examples:
+  jazz-db search \"miles\"          # Find all songs by Miles Davis
+  jazz-db search \"blue\"           # Find songs with 'blue' in title
+  jazz-db search \"monk\" --detailed # Show chord progressions")]
+    Search {
+        /// Search term (searches both title and composer)
+        term: String,
+        /// Show detailed information including chord progressions
+        #[arg(short, long, help = "Include full song structure and chord progressions")]
+        detailed: bool,
+        /// Transpose chords (only used with --detailed) by this many semitones
+        #[arg(long, conflicts_with = "to_key", help = "Semitones to transpose chords by, e.g. -2 or 3")]
+        transpose: Option<i32>,
+        /// Transpose chords (only used with --detailed) to this key
+        #[arg(long, help = "Target key to transpose chords to, e.g. Bb")]
+        to_key: Option<String>,
+    },
+    /// Filter songs by musical criteria
+    #[command(long_about = "Filter the database by specific musical criteria.
+
+Examples:
+  jazz-db filter --key C                    # All songs in C major
+  jazz-db filter --rhythm \"bossa nova\"      # All bossa nova songs
+  jazz-db filter --composer \"thelonious\"    # All Monk compositions
+  jazz-db filter --key F --rhythm swing     # F major swing songs")]
+    Filter {
+        /// Filter by key (e.g., "C", "Am", "F#")
+        #[arg(short, long, help = "Musical key (use 'jazz-db list keys' to see options)")]
+        key: Option<String>,
+        /// Filter by rhythm/style (e.g., "Swing", "Bossa Nova")
+        #[arg(short, long, help = "Rhythm/style (use 'jazz-db list rhythms' to see options)")]
+        rhythm: Option<String>,
+        /// Filter by time signature (e.g., "4/4", "3/4")
+        #[arg(short, long, help = "Time signature (use 'jazz-db list time' to see options)")]
+        time: Option<String>,
+        /// Filter by composer name (partial matching)
+        #[arg(short, long, help = "Composer name (partial matching allowed)")]
+        composer: Option<String>,
+        /// Show detailed information including chord progressions
+        #[arg(short, long, help = "Include full song structure and chord progressions")]
+        detailed: bool,
+    },
+    /// Show database statistics and analysis
+    #[command(long_about = "Display comprehensive statistics about the jazz standards database.
+
+Examples:
+  jazz-db stats            # Basic statistics
+  jazz-db stats --detailed # Top composers, keys, rhythms")]
+    Stats {
+        /// Show detailed breakdown by category
+        #[arg(short, long, help = "Show top 10 lists for keys, rhythms, and composers")]
+        detailed: bool,
+    },
+    /// List all unique values for a specific field
+    #[command(long_about = "List all unique values for database fields.
+
+Examples:
+  jazz-db list keys            # All available keys
+  jazz-db list rhythms         # All rhythm styles
+  jazz-db list composers       # All composer names
+  jazz-db list time-signatures # All time signatures")]
+    List {
+        /// Field to list: keys, rhythms, composers, time-signatures
+        #[arg(help = "Field to list", value_parser = ["keys", "rhythms", "composers", "time-signatures", "time"])]
+        field: String,
+        /// Order composers by surname ("Last, First") instead of natural order
+        #[arg(long, help = "Order composers by surname, e.g. 'Davis, Miles' (ignored for other fields)")]
+        by_surname: bool,
+    },
+    /// Show detailed information about a specific song
+    #[command(long_about = "Display complete information about a specific song including chord progressions.
+
+Examples:
+  jazz-db show \"All Blues\"
+  jazz-db show \"Giant Steps\"
+  jazz-db show \"Body and Soul\"
+  jazz-db show \"Autumn Leaves\" --transpose 2
+  jazz-db show \"Autumn Leaves\" --to-key Bb")]
+    Show {
+        /// Exact song title (case-insensitive)
+        #[arg(help = "Song title (use quotes for multi-word titles)")]
+        title: String,
+        /// Transpose chords by this many semitones before printing
+        #[arg(long, conflicts_with = "to_key", help = "Semitones to transpose chords by, e.g. -2 or 3")]
+        transpose: Option<i32>,
+        /// Transpose chords to this key before printing
+        #[arg(long, help = "Target key to transpose chords to, e.g. Bb")]
+        to_key: Option<String>,
+    },
+    /// Find clusters of songs sharing the same chord changes (contrafacts)
+    #[command(long_about = "Group songs whose chord progressions match once transposed to a common tonic,
+such as the many tunes built on rhythm changes or the blues.
+
+Examples:
+  jazz-db contrafacts")]
+    Contrafacts,
+    /// Launch an interactive terminal UI to browse the database
+    #[command(long_about = "Launch a terminal UI over the database with three panes: Composers, Songs (filtered
+by the selected composer), and Song Detail.
+
+Key bindings:
+  Up/Down  move the selection in the active pane
+  Tab      cycle Composers -> Songs -> Song Detail
+  /        incremental search (title or composer) within the selected composer's songs
+  Esc      clear the active search
+  q        quit")]
+    Browse,
+    /// Fill in missing composer/key/time-signature fields from an external
+    /// metadata source
+    #[command(long_about = "For songs missing composer, key, or time signature, query an external metadata
+source by title and propose values for the missing fields. Defaults to a dry run that
+prints a diff of proposed changes per song; pass --apply to merge them in and write the
+result back through --db.
+
+Looks up at most 25 songs per run by default, since the backing API rate-limits to about
+one request per second and this database has over a thousand incomplete records; pass
+--limit to raise or lower that cap.
+
+Examples:
+  jazz-db fetch                      # dry run, at most 25 lookups
+  jazz-db fetch --limit 200          # dry run, at most 200 lookups
+  jazz-db --db my.json fetch --apply # merge proposed changes and save")]
+    Fetch {
+        /// Merge proposed changes into the database and save (requires --db)
+        #[arg(long, help = "Merge proposed changes instead of just printing them")]
+        apply: bool,
+        /// Maximum number of songs to look up this run (default: 25)
+        #[arg(long, help = "Cap on how many songs to query this run (the API is rate-limited)")]
+        limit: Option<usize>,
+    },
+    /// Add a new song to the database (requires --db)
+    #[command(long_about = "Add a new song to the database and write it back to the file given with --db.
+
+Examples:
+  jazz-db --db my.json add \"My Tune\" --composer \"Me\" --key C --rhythm swing")]
+    Add {
+        /// Title of the new song
+        title: String,
+        /// Composer name
+        #[arg(long)]
+        composer: Option<String>,
+        /// Musical key
+        #[arg(long)]
+        key: Option<String>,
+        /// Rhythm/style
+        #[arg(long)]
+        rhythm: Option<String>,
+        /// Time signature
+        #[arg(long, value_name = "TIME_SIGNATURE")]
+        time_signature: Option<String>,
+    },
+    /// Remove a song from the database (requires --db)
+    #[command(long_about = "Remove a song from the database and write the change back to the file given with --db.
+
+Examples:
+  jazz-db --db my.json remove \"My Tune\"")]
+    Remove {
+        /// Exact song title (case-insensitive)
+        title: String,
+    },
+    /// Edit fields of an existing song, or a section's chords (requires --db)
+    #[command(long_about = "Set or clear fields on an existing song, or replace a named section's chords,
+then write the change back to the file given with --db.
+
+Examples:
+  jazz-db --db my.json edit \"My Tune\" --key Dm
+  jazz-db --db my.json edit \"My Tune\" --clear-composer
+  jazz-db --db my.json edit \"My Tune\" --section A --chords \"| Dm7 | G7 |\"")]
+    Edit {
+        /// Exact song title (case-insensitive)
+        title: String,
+        /// Set the composer
+        #[arg(long)]
+        composer: Option<String>,
+        /// Clear the composer
+        #[arg(long)]
+        clear_composer: bool,
+        /// Set the key
+        #[arg(long)]
+        key: Option<String>,
+        /// Clear the key
+        #[arg(long)]
+        clear_key: bool,
+        /// Set the rhythm
+        #[arg(long)]
+        rhythm: Option<String>,
+        /// Clear the rhythm
+        #[arg(long)]
+        clear_rhythm: bool,
+        /// Set the time signature
+        #[arg(long)]
+        time_signature: Option<String>,
+        /// Clear the time signature
+        #[arg(long)]
+        clear_time_signature: bool,
+        /// Label of the section whose main segment's chords should be replaced
+        #[arg(long, requires = "chords")]
+        section: Option<String>,
+        /// New chord text for the section named by --section
+        #[arg(long, requires = "section")]
+        chords: Option<String>,
+    },
+}
@@ -0,0 +1,92 @@
+//! Loading, saving, and mutating the Jazz Standards database.
+//!
+//! By default the database is the read-only copy embedded into the binary at
+//! compile time via `include_str!`. Passing a `--db <path>` points the tool at
+//! an external JSON file instead, which also becomes the write target for the
+//! `add`/`remove`/`edit` commands.
+
+use crate::models::{Section, Song};
+use std::error::Error;
+use std::fs;
+use std::path::Path;
+
+/// Load the embedded, read-only copy of the database.
+pub fn load_jazz_standards() -> Result<Vec<Song>, Box<dyn Error>> {
+    let json_content = include_str!("../JazzStandards.json");
+    let songs: Vec<Song> = serde_json::from_str(json_content)?;
+    Ok(songs)
+}
+
+/// Load the database from an external file, falling back to the embedded
+/// copy when no path is given.
+pub fn load(path: Option<&Path>) -> Result<Vec<Song>, Box<dyn Error>> {
+    match path {
+        Some(path) => {
+            let json_content = fs::read_to_string(path)?;
+            let songs: Vec<Song> = serde_json::from_str(&json_content)?;
+            Ok(songs)
+        }
+        None => load_jazz_standards(),
+    }
+}
+
+/// Serialize the whole database back to `path`, pretty-printed.
+pub fn save(path: &Path, songs: &[Song]) -> Result<(), Box<dyn Error>> {
+    let file = fs::File::create(path)?;
+    serde_json::to_writer_pretty(file, songs)?;
+    Ok(())
+}
+
+/// Add a new song, unless a song with the same title (case-insensitive)
+/// already exists. Returns `true` if the song was added.
+pub fn add_song(songs: &mut Vec<Song>, song: Song) -> bool {
+    if songs.iter().any(|s| s.title.eq_ignore_ascii_case(&song.title)) {
+        return false;
+    }
+    songs.push(song);
+    true
+}
+
+/// Remove the song with the given title (case-insensitive). Returns `true`
+/// if a song was removed.
+pub fn remove_song(songs: &mut Vec<Song>, title: &str) -> bool {
+    let before = songs.len();
+    songs.retain(|s| !s.title.eq_ignore_ascii_case(title));
+    songs.len() != before
+}
+
+/// Look up the song with the given title (case-insensitive) and apply `edit`
+/// to it. Returns `true` if a matching song was found.
+pub fn edit_song<F>(songs: &mut [Song], title: &str, edit: F) -> bool
+where
+    F: FnOnce(&mut Song),
+{
+    match songs.iter_mut().find(|s| s.title.eq_ignore_ascii_case(title)) {
+        Some(song) => {
+            edit(song);
+            true
+        }
+        None => false,
+    }
+}
+
+/// Set the chords of the named section's main segment on `song`, creating
+/// the section if it doesn't already exist. Returns `true` if an existing
+/// section was updated, `false` if a new one was created.
+pub fn set_section_chords(song: &mut Song, label: &str, chords: String) -> bool {
+    let sections = song.sections.get_or_insert_with(Vec::new);
+    if let Some(section) = sections
+        .iter_mut()
+        .find(|s| s.label.as_deref() == Some(label))
+    {
+        section.main_segment = Some(crate::models::Segment { chords: Some(chords) });
+        return true;
+    }
+    sections.push(Section {
+        label: Some(label.to_string()),
+        repeats: None,
+        main_segment: Some(crate::models::Segment { chords: Some(chords) }),
+        endings: None,
+    });
+    false
+}
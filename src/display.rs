@@ -0,0 +1,84 @@
+//! Printing songs to the terminal.
+
+use crate::models::Song;
+
+pub fn print_song_summary(song: &Song) {
+    println!("📄 {}", song.title);
+    if let Some(composer) = &song.composer {
+        println!("   🎵 Composer: {}", composer);
+    }
+    if let Some(key) = &song.key {
+        println!("   🎹 Key: {}", key);
+    }
+    if let Some(rhythm) = &song.rhythm {
+        println!("   🥁 Rhythm: {}", rhythm);
+    }
+    if let Some(time_sig) = &song.time_signature {
+        println!("   ⏱️  Time: {}", time_sig);
+    }
+    if let Some(sections) = &song.sections {
+        println!("   📋 Sections: {}", sections.len());
+    }
+}
+
+pub fn print_song_detailed(song: &Song) {
+    print!("{}", format_song_detailed(song));
+}
+
+/// Render the same information as [`print_song_detailed`] into a string,
+/// for callers that can't print straight to stdout (e.g. a TUI pane).
+pub fn format_song_detailed(song: &Song) -> String {
+    use std::fmt::Write;
+    let mut out = String::new();
+
+    writeln!(out, "\n═══════════════════════════════════").unwrap();
+    writeln!(out, "📄 {}", song.title).unwrap();
+    writeln!(out, "═══════════════════════════════════").unwrap();
+
+    if let Some(composer) = &song.composer {
+        writeln!(out, "🎵 Composer: {}", composer).unwrap();
+    }
+    if let Some(key) = &song.key {
+        writeln!(out, "🎹 Key: {}", key).unwrap();
+    }
+    if let Some(rhythm) = &song.rhythm {
+        writeln!(out, "🥁 Rhythm: {}", rhythm).unwrap();
+    }
+    if let Some(time_sig) = &song.time_signature {
+        writeln!(out, "⏱️  Time Signature: {}", time_sig).unwrap();
+    }
+
+    if let Some(sections) = &song.sections {
+        writeln!(out, "\n📋 Song Structure ({} sections):", sections.len()).unwrap();
+        writeln!(out, "────────────────────────────────").unwrap();
+
+        for (i, section) in sections.iter().enumerate() {
+            if let Some(label) = &section.label {
+                write!(out, "  Section {}", label).unwrap();
+                if let Some(repeats) = section.repeats {
+                    write!(out, " (repeats: {})", repeats).unwrap();
+                }
+                writeln!(out).unwrap();
+            } else {
+                writeln!(out, "  Section {}", i + 1).unwrap();
+            }
+
+            if let Some(main_seg) = &section.main_segment {
+                if let Some(chords) = &main_seg.chords {
+                    writeln!(out, "    🎼 Main: {}", chords).unwrap();
+                }
+            }
+
+            if let Some(endings) = &section.endings {
+                for (j, ending) in endings.iter().enumerate() {
+                    if let Some(chords) = &ending.chords {
+                        writeln!(out, "    🔚 Ending {}: {}", j + 1, chords).unwrap();
+                    }
+                }
+            }
+            writeln!(out).unwrap();
+        }
+    }
+
+    out
+}
@@ -0,0 +1,244 @@
+//! Filling in missing `composer`/`key`/`time_signature` fields from an
+//! external metadata source.
+//!
+//! The HTTP backend sits behind the [`MetadataProvider`] trait so it can be
+//! swapped out (a stub in tests, a different API in production) without
+//! touching the dry-run/diff/apply logic in [`Commands::Fetch`].
+//!
+//! [`Commands::Fetch`]: crate::cli::Commands::Fetch
+
+use crate::models::Song;
+use std::cell::Cell;
+use std::error::Error;
+use std::time::{Duration, Instant};
+
+/// How many songs `Commands::Fetch` looks up per run unless `--limit` says
+/// otherwise. MusicBrainz throttles to about one request per second, so an
+/// unbounded default would hammer it across the ~1,000 records missing a
+/// field.
+pub const DEFAULT_FETCH_LIMIT: usize = 25;
+
+/// Minimum gap between requests a [`MusicBrainzProvider`] will leave,
+/// matching MusicBrainz's documented rate limit of ~1 request/second.
+const MIN_REQUEST_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Metadata proposed for a song by a [`MetadataProvider`]. Fields the
+/// provider couldn't determine are `None` and left alone.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct ProposedMetadata {
+    pub composer: Option<String>,
+    pub key: Option<String>,
+    pub time_signature: Option<String>,
+}
+
+/// A source of metadata for a song, looked up by title.
+pub trait MetadataProvider {
+    fn lookup(&self, title: &str) -> Result<Option<ProposedMetadata>, Box<dyn Error>>;
+}
+
+/// Looks up a work by title against the MusicBrainz API
+/// (https://musicbrainz.org/doc/MusicBrainz_API): a search by title finds
+/// the work's MBID, then a follow-up lookup with `inc=artist-rels` fetches
+/// its relationships, since search results don't include them. Only
+/// `composer` is populated today: MusicBrainz's work relations include the
+/// credited composer, but it doesn't model jazz-specific fields like key or
+/// time signature.
+pub struct MusicBrainzProvider {
+    client: reqwest::blocking::Client,
+    last_request_at: Cell<Option<Instant>>,
+}
+
+impl MusicBrainzProvider {
+    pub fn new() -> Self {
+        let client = reqwest::blocking::Client::builder()
+            .user_agent("jazz-standards-database/0.1 (https://github.com/gsspdev/jazz_standards_database)")
+            .build()
+            .expect("failed to build HTTP client");
+        MusicBrainzProvider { client, last_request_at: Cell::new(None) }
+    }
+
+    /// Block until at least [`MIN_REQUEST_INTERVAL`] has passed since the
+    /// last request this provider made.
+    fn throttle(&self) {
+        if let Some(last) = self.last_request_at.get() {
+            let elapsed = last.elapsed();
+            if elapsed < MIN_REQUEST_INTERVAL {
+                std::thread::sleep(MIN_REQUEST_INTERVAL - elapsed);
+            }
+        }
+        self.last_request_at.set(Some(Instant::now()));
+    }
+}
+
+impl Default for MusicBrainzProvider {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Percent-encode a string for use in a URL query component (RFC 3986
+/// unreserved characters pass through; everything else becomes `%XX`).
+fn percent_encode(input: &str) -> String {
+    let mut out = String::with_capacity(input.len());
+    for byte in input.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => out.push(byte as char),
+            _ => out.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+    out
+}
+
+impl MetadataProvider for MusicBrainzProvider {
+    fn lookup(&self, title: &str) -> Result<Option<ProposedMetadata>, Box<dyn Error>> {
+        // The search endpoint doesn't return relationships, so first find
+        // the work's MBID, then look that up with `inc=artist-rels` to get
+        // the composer relation.
+        self.throttle();
+        let query = percent_encode(&format!("work:\"{}\"", title.replace('"', "\\\"")));
+        let search_url = format!("https://musicbrainz.org/ws/2/work/?query={}&fmt=json", query);
+        let search_response: serde_json::Value = self.client.get(&search_url).send()?.json()?;
+
+        let Some(mbid) = search_response
+            .get("works")
+            .and_then(|works| works.as_array())
+            .and_then(|works| works.first())
+            .and_then(|work| work.get("id"))
+            .and_then(|id| id.as_str())
+        else {
+            return Ok(None);
+        };
+
+        self.throttle();
+        let lookup_url = format!("https://musicbrainz.org/ws/2/work/{}?inc=artist-rels&fmt=json", mbid);
+        let work: serde_json::Value = self.client.get(&lookup_url).send()?.json()?;
+
+        let composer = work
+            .get("relations")
+            .and_then(|relations| relations.as_array())
+            .and_then(|relations| relations.iter().find(|r| r.get("type") == Some(&serde_json::Value::String("composer".into()))))
+            .and_then(|relation| relation.get("artist"))
+            .and_then(|artist| artist.get("name"))
+            .and_then(|name| name.as_str())
+            .map(|name| name.to_string());
+
+        if composer.is_none() {
+            return Ok(None);
+        }
+
+        Ok(Some(ProposedMetadata { composer, key: None, time_signature: None }))
+    }
+}
+
+/// Which missing fields on `song` a [`ProposedMetadata`] can fill in.
+/// Fields the song already has are never overwritten.
+pub fn diff_for(song: &Song, proposed: &ProposedMetadata) -> ProposedMetadata {
+    ProposedMetadata {
+        composer: if song.composer.is_none() { proposed.composer.clone() } else { None },
+        key: if song.key.is_none() { proposed.key.clone() } else { None },
+        time_signature: if song.time_signature.is_none() { proposed.time_signature.clone() } else { None },
+    }
+}
+
+impl ProposedMetadata {
+    /// Whether this diff actually proposes any new values.
+    pub fn is_empty(&self) -> bool {
+        self.composer.is_none() && self.key.is_none() && self.time_signature.is_none()
+    }
+}
+
+/// Apply a diff's non-`None` fields onto `song`.
+pub fn apply_diff(song: &mut Song, diff: &ProposedMetadata) {
+    if let Some(composer) = &diff.composer {
+        song.composer = Some(composer.clone());
+    }
+    if let Some(key) = &diff.key {
+        song.key = Some(key.clone());
+    }
+    if let Some(time_signature) = &diff.time_signature {
+        song.time_signature = Some(time_signature.clone());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    #[test]
+    fn percent_encode_escapes_reserved_characters() {
+        assert_eq!(percent_encode("Don't Get Around Much Anymore"), "Don%27t%20Get%20Around%20Much%20Anymore");
+        assert_eq!(percent_encode("work:\"A & B\""), "work%3A%22A%20%26%20B%22");
+    }
+
+    /// An in-memory [`MetadataProvider`] for tests, with no network access.
+    struct StubProvider(HashMap<&'static str, ProposedMetadata>);
+
+    impl MetadataProvider for StubProvider {
+        fn lookup(&self, title: &str) -> Result<Option<ProposedMetadata>, Box<dyn Error>> {
+            Ok(self.0.get(title).cloned())
+        }
+    }
+
+    fn song(title: &str, composer: Option<&str>, key: Option<&str>) -> Song {
+        Song {
+            title: title.to_string(),
+            composer: composer.map(str::to_string),
+            key: key.map(str::to_string),
+            rhythm: None,
+            time_signature: None,
+            sections: None,
+        }
+    }
+
+    #[test]
+    fn diff_only_proposes_missing_fields() {
+        let existing = song("Blue in Green", Some("Miles Davis"), None);
+        let proposed = ProposedMetadata {
+            composer: Some("Someone Else".to_string()),
+            key: Some("Bb".to_string()),
+            time_signature: None,
+        };
+
+        let diff = diff_for(&existing, &proposed);
+
+        assert_eq!(diff.composer, None, "existing composer must not be overwritten");
+        assert_eq!(diff.key, Some("Bb".to_string()));
+        assert!(diff.time_signature.is_none());
+        assert!(!diff.is_empty());
+    }
+
+    #[test]
+    fn apply_diff_fills_in_missing_fields_only() {
+        let mut existing = song("Blue in Green", None, Some("Bb"));
+        let diff = ProposedMetadata {
+            composer: Some("Miles Davis".to_string()),
+            key: Some("Should not apply".to_string()),
+            time_signature: None,
+        };
+
+        // Simulate the real flow: diff_for already excludes fields the song
+        // has, so only the composer should end up proposed here.
+        let diff = diff_for(&existing, &diff);
+        apply_diff(&mut existing, &diff);
+
+        assert_eq!(existing.composer.as_deref(), Some("Miles Davis"));
+        assert_eq!(existing.key.as_deref(), Some("Bb"), "pre-existing key must survive apply");
+    }
+
+    #[test]
+    fn stub_provider_round_trip() {
+        let mut data = HashMap::new();
+        data.insert(
+            "Blue in Green",
+            ProposedMetadata { composer: Some("Miles Davis".to_string()), key: None, time_signature: None },
+        );
+        let provider = StubProvider(data);
+
+        let found = provider.lookup("Blue in Green").unwrap();
+        assert_eq!(found.unwrap().composer.as_deref(), Some("Miles Davis"));
+
+        let missing = provider.lookup("Nonexistent Tune").unwrap();
+        assert!(missing.is_none());
+    }
+}
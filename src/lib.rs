@@ -7,6 +7,11 @@
 pub mod models;
 pub mod database;
 pub mod search;
+pub mod analysis;
+pub mod theory;
+pub mod transpose;
+pub mod browse;
+pub mod fetch;
 pub mod display;
 pub mod stats;
 pub mod cli;
@@ -15,6 +20,7 @@ pub mod cli;
 pub use models::{Song, Section, Segment};
 pub use database::load_jazz_standards;
 pub use search::{search_songs, filter_songs};
+pub use analysis::find_contrafacts;
 pub use display::{print_song_summary, print_song_detailed};
 pub use stats::{show_statistics, list_field_values};
 pub use cli::{Cli, Commands};
\ No newline at end of file
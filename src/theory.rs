@@ -0,0 +1,120 @@
+//! Shared music-theory helpers: pitch classes and note spelling.
+//!
+//! Used by [`crate::analysis`] (to normalize progressions for contrafact
+//! matching) and [`crate::transpose`] (to rewrite chords into another key).
+
+const SHARP_NAMES: [&str; 12] = ["C", "C#", "D", "D#", "E", "F", "F#", "G", "G#", "A", "A#", "B"];
+const FLAT_NAMES: [&str; 12] = ["C", "Db", "D", "Eb", "E", "F", "Gb", "G", "Ab", "A", "Bb", "B"];
+
+/// Map a root letter (A-G) plus an optional `#`/`b` accidental to a pitch
+/// class in 0-11, treating enharmonic spellings (e.g. C#/Db) as equal.
+pub fn pitch_class(letter: char, accidental: Option<char>) -> Option<u8> {
+    let base = match letter.to_ascii_uppercase() {
+        'C' => 0,
+        'D' => 2,
+        'E' => 4,
+        'F' => 5,
+        'G' => 7,
+        'A' => 9,
+        'B' => 11,
+        _ => return None,
+    };
+    let offset = match accidental {
+        Some('#') => 1,
+        Some('b') => -1,
+        None => 0,
+        _ => return None,
+    };
+    Some(((base + offset).rem_euclid(12)) as u8)
+}
+
+/// Parse the leading root (letter plus optional accidental) off a note or
+/// key name such as `"Bb"`, `"F#m"`, or `"C"`, ignoring any trailing mode or
+/// quality text.
+pub fn parse_root(name: &str) -> Option<u8> {
+    let mut chars = name.trim().chars();
+    let letter = chars.next()?;
+    if !letter.is_ascii_alphabetic() {
+        return None;
+    }
+    let accidental = match chars.next() {
+        Some(c @ ('#' | 'b')) => Some(c),
+        _ => None,
+    };
+    pitch_class(letter, accidental)
+}
+
+/// Whether a key name conventionally uses flat spellings (F, Bb, Eb, Ab, Db,
+/// Gb, Cb and their minors) rather than sharps.
+///
+/// This goes off the key's *written* accidental, not its pitch class: B and
+/// Cb share a pitch class but B major is spelled with sharps while Cb major
+/// is spelled with flats, and the same goes for F#/Gb and C#/Db.
+pub fn prefers_flats(key: &str) -> bool {
+    let mut chars = key.trim().chars();
+    let Some(letter) = chars.next() else { return false };
+    if !letter.is_ascii_alphabetic() {
+        return false;
+    }
+    match chars.next() {
+        Some('b') => true,
+        Some('#') => false,
+        // No written accidental: of the natural-letter major keys, only F
+        // is conventionally spelled with flats (it has a single flat, Bb);
+        // of the natural-letter minor keys, D, G, C and F minor are.
+        next => {
+            let minor = next == Some('m') && !chars.as_str().starts_with("aj");
+            let letter = letter.to_ascii_uppercase();
+            if minor {
+                matches!(letter, 'D' | 'G' | 'C' | 'F')
+            } else {
+                letter == 'F'
+            }
+        }
+    }
+}
+
+/// Spell a pitch class (0-11) as a note name, preferring flats or sharps.
+pub fn spell(pitch_class: u8, flats: bool) -> &'static str {
+    let table = if flats { &FLAT_NAMES } else { &SHARP_NAMES };
+    table[pitch_class as usize % 12]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pitch_class_maps_letters_and_accidentals() {
+        assert_eq!(pitch_class('C', None), Some(0));
+        assert_eq!(pitch_class('B', Some('#')), Some(0));
+        assert_eq!(pitch_class('C', Some('b')), Some(11));
+        assert_eq!(pitch_class('H', None), None);
+    }
+
+    #[test]
+    fn pitch_class_treats_enharmonics_as_equal() {
+        assert_eq!(pitch_class('C', Some('#')), pitch_class('D', Some('b')));
+        assert_eq!(pitch_class('F', Some('#')), pitch_class('G', Some('b')));
+    }
+
+    #[test]
+    fn prefers_flats_goes_off_written_accidental_not_pitch_class() {
+        // B and Cb share a pitch class but B major uses sharps, Cb major flats.
+        assert!(!prefers_flats("B"));
+        assert!(prefers_flats("Cb"));
+        // F# and Gb likewise.
+        assert!(!prefers_flats("F#"));
+        assert!(prefers_flats("Gb"));
+        // C# and Db likewise.
+        assert!(!prefers_flats("C#"));
+        assert!(prefers_flats("Db"));
+    }
+
+    #[test]
+    fn prefers_flats_defaults_f_to_flats_and_others_to_sharps() {
+        assert!(prefers_flats("F"));
+        assert!(!prefers_flats("C"));
+        assert!(!prefers_flats("G"));
+    }
+}
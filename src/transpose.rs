@@ -0,0 +1,168 @@
+//! Rewriting a song's chords into a different key.
+
+use crate::models::{Section, Segment, Song};
+use crate::theory;
+
+/// Transpose a single chord symbol (e.g. `"Dm7"`, `"G7/B"`) by `interval`
+/// semitones, re-spelling the root (and bass note, if present) with flats or
+/// sharps per `flats`. Quality suffixes are preserved verbatim. Tokens that
+/// don't parse as chords (bar lines, repeat markers, `%`) pass through
+/// unchanged.
+fn transpose_token(token: &str, interval: i32, flats: bool) -> String {
+    let mut parts = token.splitn(2, '/');
+    let Some(chord) = parts.next() else { return token.to_string() };
+    let bass = parts.next();
+
+    let Some(transposed_chord) = transpose_root(chord, interval, flats) else {
+        return token.to_string();
+    };
+    match bass.and_then(|b| transpose_root(b, interval, flats)) {
+        Some(transposed_bass) => format!("{}/{}", transposed_chord, transposed_bass),
+        None => match bass {
+            Some(b) => format!("{}/{}", transposed_chord, b),
+            None => transposed_chord,
+        },
+    }
+}
+
+/// Transpose the root of a chord or bare note, keeping any trailing quality
+/// text (e.g. `maj7`, `m7b5`) attached as-is.
+fn transpose_root(text: &str, interval: i32, flats: bool) -> Option<String> {
+    let mut chars = text.chars();
+    let letter = chars.next()?;
+    if !letter.is_ascii_alphabetic() {
+        return None;
+    }
+    let rest = chars.as_str();
+    let (accidental, quality) = match rest.chars().next() {
+        Some(c @ ('#' | 'b')) => (Some(c), &rest[1..]),
+        _ => (None, rest),
+    };
+    let root = theory::pitch_class(letter, accidental)?;
+    let new_root = ((root as i32 + interval).rem_euclid(12)) as u8;
+    Some(format!("{}{}", theory::spell(new_root, flats), quality))
+}
+
+/// Transpose every chord token in `chords`, leaving bar lines, repeat
+/// markers, and spacing between tokens untouched.
+fn transpose_chords_text(chords: &str, interval: i32, flats: bool) -> String {
+    let mut out = String::new();
+    for piece in chords.split_inclusive(char::is_whitespace) {
+        let raw_token = piece.trim_end_matches(char::is_whitespace);
+        if raw_token.chars().all(|c| c == '|' || c == ':' || c == '%') {
+            out.push_str(raw_token);
+        } else {
+            out.push_str(&transpose_token(raw_token, interval, flats));
+        }
+        out.push_str(&piece[raw_token.len()..]);
+    }
+    out
+}
+
+fn transpose_segment(segment: &Segment, interval: i32, flats: bool) -> Segment {
+    Segment {
+        chords: segment
+            .chords
+            .as_deref()
+            .map(|c| transpose_chords_text(c, interval, flats)),
+    }
+}
+
+fn transpose_section(section: &Section, interval: i32, flats: bool) -> Section {
+    Section {
+        label: section.label.clone(),
+        repeats: section.repeats,
+        main_segment: section
+            .main_segment
+            .as_ref()
+            .map(|s| transpose_segment(s, interval, flats)),
+        endings: section
+            .endings
+            .as_ref()
+            .map(|endings| endings.iter().map(|s| transpose_segment(s, interval, flats)).collect()),
+    }
+}
+
+/// Return a copy of `song` with every chord transposed by `interval`
+/// semitones. Bar lines, repeats, and section labels are left untouched.
+pub fn transpose_song(song: &Song, interval: i32, flats: bool) -> Song {
+    Song {
+        title: song.title.clone(),
+        composer: song.composer.clone(),
+        key: song.key.clone(),
+        rhythm: song.rhythm.clone(),
+        time_signature: song.time_signature.clone(),
+        sections: song
+            .sections
+            .as_ref()
+            .map(|sections| sections.iter().map(|s| transpose_section(s, interval, flats)).collect()),
+    }
+}
+
+/// Resolve the semitone interval and flat/sharp spelling preference to use
+/// for a transposition, given either an explicit `--transpose` offset or a
+/// `--to-key` target (relative to the song's own key, defaulting to C).
+pub fn resolve_transposition(song: &Song, semitones: Option<i32>, to_key: Option<&str>) -> (i32, bool) {
+    if let Some(target) = to_key {
+        let target_root = theory::parse_root(target).unwrap_or(0);
+        let source_root = song.key.as_deref().and_then(theory::parse_root).unwrap_or(0);
+        let interval = (target_root as i32 - source_root as i32).rem_euclid(12);
+        (interval, theory::prefers_flats(target))
+    } else {
+        let interval = semitones.unwrap_or(0);
+        let flats = song.key.as_deref().map(theory::prefers_flats).unwrap_or(false);
+        (interval, flats)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn transpose_token_preserves_quality_suffix() {
+        assert_eq!(transpose_token("Dm7", 2, false), "Em7");
+        assert_eq!(transpose_token("Cmaj7", -1, false), "Bmaj7");
+    }
+
+    #[test]
+    fn transpose_token_respects_flat_vs_sharp_spelling() {
+        assert_eq!(transpose_token("C7", 1, false), "C#7");
+        assert_eq!(transpose_token("C7", 1, true), "Db7");
+    }
+
+    #[test]
+    fn transpose_token_transposes_bass_note_too() {
+        assert_eq!(transpose_token("G7/B", 2, false), "A7/C#");
+    }
+
+    #[test]
+    fn transpose_token_leaves_bar_lines_and_repeats_unchanged() {
+        assert_eq!(transpose_token("|", 3, false), "|");
+        assert_eq!(transpose_token(":", 3, false), ":");
+    }
+
+    #[test]
+    fn transpose_song_leaves_section_labels_and_repeats_alone() {
+        let song = Song {
+            title: "Tune".to_string(),
+            composer: None,
+            key: Some("C".to_string()),
+            rhythm: None,
+            time_signature: None,
+            sections: Some(vec![Section {
+                label: Some("A".to_string()),
+                repeats: Some(2),
+                main_segment: Some(Segment { chords: Some("| C7 | F7 |".to_string()) }),
+                endings: None,
+            }]),
+        };
+
+        let transposed = transpose_song(&song, 2, false);
+        let section = &transposed.sections.unwrap()[0];
+
+        assert_eq!(section.label.as_deref(), Some("A"));
+        assert_eq!(section.repeats, Some(2));
+        assert_eq!(section.main_segment.as_ref().unwrap().chords.as_deref(), Some("| D7 | G7 |"));
+    }
+}